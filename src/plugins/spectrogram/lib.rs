@@ -1,7 +1,8 @@
 mod utils;
 use wasm_bindgen::prelude::*;
-use rustfft::{FftPlanner, num_complex::Complex};
+use realfft::{num_complex::Complex, RealFftPlanner, RealToComplex};
 use std::f32::consts::PI;
+use std::sync::Arc;
 
 // Import the `console.log` function from the `console` namespace
 #[wasm_bindgen]
@@ -22,9 +23,11 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 #[wasm_bindgen]
 pub struct WasmFFT {
     size: usize,
-    planner: FftPlanner<f32>,
+    r2c: Arc<dyn RealToComplex<f32>>,
     window: Vec<f32>,
-    scratch: Vec<Complex<f32>>,
+    real_scratch: Vec<f32>,
+    spectrum_scratch: Vec<Complex<f32>>,
+    num_frames: usize,
 }
 
 #[wasm_bindgen]
@@ -32,20 +35,29 @@ impl WasmFFT {
     #[wasm_bindgen(constructor)]
     pub fn new(size: usize, window_type: &str, alpha: Option<f32>) -> Result<WasmFFT, JsValue> {
         utils::set_panic_hook();
-        
+
         // Validate that size is a power of 2
         if !size.is_power_of_two() {
             return Err(JsValue::from_str("FFT size must be a power of 2"));
         }
-        
+
         let window = create_window(size, window_type, alpha.unwrap_or(0.16))?;
-        let scratch = vec![Complex::new(0.0, 0.0); size];
-        
+
+        // Real input has conjugate-symmetric output, so a real-to-complex
+        // plan does roughly half the work of a full complex FFT and is
+        // planned once here instead of on every call.
+        let mut planner = RealFftPlanner::<f32>::new();
+        let r2c = planner.plan_fft_forward(size);
+        let real_scratch = r2c.make_input_vec();
+        let spectrum_scratch = r2c.make_output_vec();
+
         Ok(WasmFFT {
             size,
-            planner: FftPlanner::new(),
+            r2c,
             window,
-            scratch,
+            real_scratch,
+            spectrum_scratch,
+            num_frames: 0,
         })
     }
 
@@ -53,38 +65,408 @@ impl WasmFFT {
     pub fn calculate_spectrum(&mut self, input: &[f32]) -> Result<Vec<f32>, JsValue> {
         if input.len() != self.size {
             return Err(JsValue::from_str(&format!(
-                "Input buffer size {} does not match FFT size {}", 
-                input.len(), 
+                "Input buffer size {} does not match FFT size {}",
+                input.len(),
                 self.size
             )));
         }
 
-        let fft = self.planner.plan_fft_forward(self.size);
-        
-        // Apply window and convert to complex
-        for (i, (sample, window_val)) in input.iter().zip(self.window.iter()).enumerate() {
-            self.scratch[i] = Complex::new(sample * window_val, 0.0);
-        }
-        
-        // Perform FFT
-        fft.process(&mut self.scratch);
-        
+        self.window_and_transform(input)?;
+
         // Calculate magnitudes (only first half due to symmetry)
         let mut spectrum = Vec::with_capacity(self.size / 2);
         let scale = 2.0 / self.size as f32;
-        
+
         for i in 0..self.size / 2 {
-            let magnitude = self.scratch[i].norm() * scale;
+            let magnitude = self.spectrum_scratch[i].norm() * scale;
             spectrum.push(magnitude);
         }
-        
+
         Ok(spectrum)
     }
 
+    /// Slides a window across `signal` with the given `hop_size` and returns
+    /// a flattened `num_frames * (size / 2)` magnitude matrix, avoiding a
+    /// WASM boundary crossing and allocation per frame. The trailing partial
+    /// frame, if any, is zero-padded.
+    #[wasm_bindgen]
+    pub fn calculate_spectrogram(
+        &mut self,
+        signal: &[f32],
+        hop_size: usize,
+    ) -> Result<Vec<f32>, JsValue> {
+        if hop_size == 0 {
+            return Err(JsValue::from_str("hop_size must be greater than 0"));
+        }
+        if signal.is_empty() {
+            return Err(JsValue::from_str("signal must not be empty"));
+        }
+
+        let bins = self.size / 2;
+        let num_frames = if signal.len() <= self.size {
+            1
+        } else {
+            1 + (signal.len() - self.size + hop_size - 1) / hop_size
+        };
+
+        let mut spectrogram = Vec::with_capacity(num_frames * bins);
+        let mut frame = vec![0.0f32; self.size];
+        let scale = 2.0 / self.size as f32;
+
+        for frame_idx in 0..num_frames {
+            let start = frame_idx * hop_size;
+            let end = (start + self.size).min(signal.len());
+
+            frame.fill(0.0);
+            if start < end {
+                frame[..end - start].copy_from_slice(&signal[start..end]);
+            }
+
+            self.window_and_transform(&frame)?;
+
+            for i in 0..bins {
+                spectrogram.push(self.spectrum_scratch[i].norm() * scale);
+            }
+        }
+
+        self.num_frames = num_frames;
+        Ok(spectrogram)
+    }
+
+    /// Welch's method: averages the squared-magnitude periodogram of
+    /// overlapping, windowed segments of `signal` into a single one-sided
+    /// power spectral density, trading frequency resolution for a much
+    /// lower-variance noise floor than a single `calculate_spectrum` frame.
+    #[wasm_bindgen]
+    pub fn calculate_psd(
+        &mut self,
+        signal: &[f32],
+        hop_size: usize,
+        sample_rate: f32,
+    ) -> Result<Vec<f32>, JsValue> {
+        if hop_size == 0 {
+            return Err(JsValue::from_str("hop_size must be greater than 0"));
+        }
+        if signal.len() < self.size {
+            return Err(JsValue::from_str("signal is shorter than the FFT size"));
+        }
+
+        let bins = self.size / 2 + 1;
+        let mut psd = vec![0.0f32; bins];
+        let window_power: f32 = self.window.iter().map(|w| w * w).sum();
+        let mut num_segments = 0usize;
+
+        let mut start = 0;
+        while start + self.size <= signal.len() {
+            self.window_and_transform(&signal[start..start + self.size])?;
+
+            for k in 0..bins {
+                psd[k] += self.spectrum_scratch[k].norm_sqr();
+            }
+
+            num_segments += 1;
+            start += hop_size;
+        }
+
+        if num_segments == 0 {
+            return Err(JsValue::from_str("no complete segments fit in signal"));
+        }
+
+        // One-sided PSD: normalize by window power, sample rate and segment
+        // count, then double the interior bins (DC and Nyquist stay as-is)
+        // since their energy isn't mirrored into the discarded negative half.
+        let norm = 1.0 / (sample_rate * window_power * num_segments as f32);
+        for (k, value) in psd.iter_mut().enumerate() {
+            *value *= norm;
+            if k != 0 && k != bins - 1 {
+                *value *= 2.0;
+            }
+        }
+
+        Ok(psd)
+    }
+
     #[wasm_bindgen(getter)]
     pub fn size(&self) -> usize {
         self.size
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+}
+
+impl WasmFFT {
+    /// Windows `frame` into `real_scratch` and runs the cached real-input
+    /// FFT into `spectrum_scratch`. `frame` must be exactly `self.size` long.
+    fn window_and_transform(&mut self, frame: &[f32]) -> Result<(), JsValue> {
+        for (i, (sample, window_val)) in frame.iter().zip(self.window.iter()).enumerate() {
+            self.real_scratch[i] = sample * window_val;
+        }
+
+        self.r2c
+            .process(&mut self.real_scratch, &mut self.spectrum_scratch)
+            .map_err(|e| JsValue::from_str(&format!("FFT failed: {}", e)))
+    }
+}
+
+/// Multitaper spectral estimation using Slepian (DPSS) tapers.
+///
+/// Where Welch's method trades frequency resolution for lower variance by
+/// averaging over time segments, multitaper estimation keeps the full
+/// window length and instead averages over `K` orthogonal tapers, each
+/// leaking energy into a different part of the spectrum. Averaging the
+/// resulting eigenspectra cancels much of that leakage without shortening
+/// the analysis window.
+#[wasm_bindgen]
+pub struct WasmMultitaper {
+    size: usize,
+    tapers: Vec<Vec<f32>>,
+    r2c: Arc<dyn RealToComplex<f32>>,
+    real_scratch: Vec<f32>,
+    spectrum_scratch: Vec<Complex<f32>>,
+}
+
+#[wasm_bindgen]
+impl WasmMultitaper {
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize, nw: f32) -> Result<WasmMultitaper, JsValue> {
+        utils::set_panic_hook();
+
+        if !size.is_power_of_two() {
+            return Err(JsValue::from_str("FFT size must be a power of 2"));
+        }
+        if nw <= 0.0 {
+            return Err(JsValue::from_str("nw must be positive"));
+        }
+
+        let num_tapers = ((2.0 * nw).floor() as usize).saturating_sub(1).max(1);
+        let tapers = generate_dpss_tapers(size, nw, num_tapers);
+
+        let mut planner = RealFftPlanner::<f32>::new();
+        let r2c = planner.plan_fft_forward(size);
+        let real_scratch = r2c.make_input_vec();
+        let spectrum_scratch = r2c.make_output_vec();
+
+        Ok(WasmMultitaper {
+            size,
+            tapers,
+            r2c,
+            real_scratch,
+            spectrum_scratch,
+        })
+    }
+
+    /// Windows `input` by each taper, FFTs it, and averages the `K`
+    /// resulting eigenspectra into a single N/2+1 power spectrum.
+    #[wasm_bindgen]
+    pub fn estimate(&mut self, input: &[f32]) -> Result<Vec<f32>, JsValue> {
+        if input.len() != self.size {
+            return Err(JsValue::from_str(&format!(
+                "Input buffer size {} does not match taper size {}",
+                input.len(),
+                self.size
+            )));
+        }
+
+        let bins = self.size / 2 + 1;
+        let mut power = vec![0.0f32; bins];
+
+        for taper in &self.tapers {
+            for (i, (sample, taper_val)) in input.iter().zip(taper.iter()).enumerate() {
+                self.real_scratch[i] = sample * taper_val;
+            }
+
+            self.r2c
+                .process(&mut self.real_scratch, &mut self.spectrum_scratch)
+                .map_err(|e| JsValue::from_str(&format!("FFT failed: {}", e)))?;
+
+            for k in 0..bins {
+                power[k] += self.spectrum_scratch[k].norm_sqr();
+            }
+        }
+
+        let num_tapers = self.tapers.len() as f32;
+        for value in power.iter_mut() {
+            *value /= num_tapers;
+        }
+
+        Ok(power)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn num_tapers(&self) -> usize {
+        self.tapers.len()
+    }
+}
+
+/// Generates the `k` leading discrete prolate spheroidal sequences (DPSS,
+/// a.k.a. Slepian tapers) of length `n` for time-bandwidth product `nw`.
+///
+/// The tapers are the eigenvectors of the symmetric tridiagonal matrix with
+/// diagonal `((n-1)/2 - i)^2 * cos(2*pi*W)` (`W = nw/n`) and off-diagonal
+/// `i*(n-i)/2`, ordered by decreasing eigenvalue (most concentrated first).
+/// Only the top `k` eigenpairs are ever needed (`k` is typically 4-7), so
+/// each one is located individually via bisection on the Sturm sequence
+/// count plus inverse iteration, rather than diagonalizing the full `n x n`
+/// matrix: that keeps the cost at `O(n * k)` instead of `O(n^3)`, which
+/// matters since FFT sizes in the 1024-4096 range are ordinary spectrogram
+/// settings.
+fn generate_dpss_tapers(n: usize, nw: f32, k: usize) -> Vec<Vec<f32>> {
+    let w = nw / n as f32;
+    let two_pi_w_cos = (2.0 * PI as f64 * w as f64).cos();
+
+    let mut diag = vec![0.0f64; n];
+    let mut off_diag = vec![0.0f64; n.saturating_sub(1)];
+
+    for (i, d) in diag.iter_mut().enumerate() {
+        let center = (n as f64 - 1.0) / 2.0 - i as f64;
+        *d = center * center * two_pi_w_cos;
+    }
+    for (i, e) in off_diag.iter_mut().enumerate() {
+        let a = (i + 1) as f64;
+        let b = (n - i - 1) as f64;
+        *e = a * b / 2.0;
+    }
+
+    let k = k.min(n);
+    let mut tapers = Vec::with_capacity(k);
+
+    // Eigenvalues are wanted largest-first; rank `n - 1` (0-indexed,
+    // ascending) is the largest.
+    for order in 0..k {
+        let target_rank = n - 1 - order;
+        let lambda = find_tridiagonal_eigenvalue(&diag, &off_diag, target_rank);
+        let eigenvector = inverse_iterate(&diag, &off_diag, lambda, order);
+
+        let mut taper: Vec<f32> = eigenvector.iter().map(|&v| v as f32).collect();
+        let energy: f32 = taper.iter().map(|v| v * v).sum();
+        if energy > 0.0 {
+            let norm = energy.sqrt();
+            for v in taper.iter_mut() {
+                *v /= norm;
+            }
+        }
+        tapers.push(taper);
+    }
+
+    tapers
+}
+
+/// Counts the eigenvalues of the symmetric tridiagonal matrix (`diag`,
+/// `off_diag`) that are strictly less than `x`, via the Sturm sequence
+/// built from Sylvester's law of inertia (the same count LAPACK's `stebz`
+/// uses to bisect for a specific eigenvalue rank).
+fn sturm_count(diag: &[f64], off_diag: &[f64], x: f64) -> usize {
+    let mut d = diag[0] - x;
+    let mut count = (d < 0.0) as usize;
+
+    for i in 1..diag.len() {
+        let e = off_diag[i - 1];
+        let denom = if d.abs() < 1e-300 { 1e-300 } else { d };
+        d = (diag[i] - x) - (e * e) / denom;
+        if d < 0.0 {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Finds the eigenvalue of rank `target_rank` (0-indexed ascending, so
+/// `n - 1` is the largest) of the symmetric tridiagonal matrix (`diag`,
+/// `off_diag`) by bisecting on Gershgorin bounds using `sturm_count`.
+fn find_tridiagonal_eigenvalue(diag: &[f64], off_diag: &[f64], target_rank: usize) -> f64 {
+    let n = diag.len();
+
+    let mut lo = f64::INFINITY;
+    let mut hi = f64::NEG_INFINITY;
+    for i in 0..n {
+        let radius = off_diag.get(i.wrapping_sub(1)).copied().unwrap_or(0.0).abs()
+            + off_diag.get(i).copied().unwrap_or(0.0).abs();
+        lo = lo.min(diag[i] - radius);
+        hi = hi.max(diag[i] + radius);
+    }
+
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        if mid == lo || mid == hi {
+            break;
+        }
+        if sturm_count(diag, off_diag, mid) <= target_rank {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+/// Recovers the eigenvector for an (approximate) eigenvalue `lambda` of the
+/// symmetric tridiagonal matrix (`diag`, `off_diag`) via inverse iteration:
+/// repeatedly solving `(A - lambda*I) v' = v` and renormalizing converges
+/// to the eigenvector in a handful of `O(n)` tridiagonal solves. `seed`
+/// varies the deterministic starting vector across calls so consecutive
+/// tapers don't start from near-identical guesses.
+fn inverse_iterate(diag: &[f64], off_diag: &[f64], lambda: f64, seed: usize) -> Vec<f64> {
+    let n = diag.len();
+    // Nudge the shift off the exact eigenvalue so (A - lambda*I) stays
+    // invertible enough for the tridiagonal solve.
+    let shifted_diag: Vec<f64> = diag.iter().map(|d| d - lambda - 1e-10).collect();
+
+    let mut v: Vec<f64> = (0..n)
+        .map(|i| ((i as f64 + 1.0) * (seed as f64 + 1.7)).sin())
+        .collect();
+    normalize(&mut v);
+
+    for _ in 0..30 {
+        solve_tridiagonal(&shifted_diag, off_diag, &mut v);
+        normalize(&mut v);
+    }
+
+    v
+}
+
+fn normalize(v: &mut [f64]) {
+    let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for x in v.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
+/// Solves the symmetric tridiagonal system `A * x = rhs` in place via the
+/// Thomas algorithm, where `A` has diagonal `diag` and off-diagonal
+/// `off_diag` (`off_diag[i]` couples rows `i` and `i + 1`).
+fn solve_tridiagonal(diag: &[f64], off_diag: &[f64], rhs: &mut [f64]) {
+    let n = diag.len();
+    let mut c_prime = vec![0.0; n.saturating_sub(1)];
+    let mut d_prime = vec![0.0; n];
+
+    d_prime[0] = rhs[0] / diag[0];
+    if n > 1 {
+        c_prime[0] = off_diag[0] / diag[0];
+    }
+
+    for i in 1..n {
+        let e_prev = off_diag[i - 1];
+        let mut denom = diag[i] - e_prev * c_prime.get(i - 1).copied().unwrap_or(0.0);
+        if denom.abs() < 1e-300 {
+            denom = 1e-300_f64.copysign(denom);
+        }
+        if i < n - 1 {
+            c_prime[i] = off_diag[i] / denom;
+        }
+        d_prime[i] = (rhs[i] - e_prev * d_prime[i - 1]) / denom;
+    }
+
+    rhs[n - 1] = d_prime[n - 1];
+    for i in (0..n - 1).rev() {
+        rhs[i] = d_prime[i] - c_prime[i] * rhs[i + 1];
+    }
 }
 
 #[wasm_bindgen]
@@ -283,44 +665,291 @@ fn create_filter_bank(
         _ => return Err(JsValue::from_str(&format!("Unknown scale type: {}", scale_type))),
     };
 
+    let num_bins = fft_size / 2 + 1;
+    let bin_hz = sample_rate / fft_size as f32;
+
     let filter_min = hz_to_scale(0.0);
     let filter_max = hz_to_scale(sample_rate / 2.0);
-    let mut filter_bank = vec![vec![0.0; fft_size / 2 + 1]; num_filters];
-    let scale = sample_rate / fft_size as f32;
+
+    // num_filters+2 points equally spaced on the chosen scale give the
+    // low/center/high edge (in FFT bins) of each triangular filter; adjacent
+    // filters share an edge so the filter bank tiles the spectrum without
+    // gaps or double-counted energy.
+    let edge_bins: Vec<f32> = (0..num_filters + 2)
+        .map(|i| {
+            let point =
+                filter_min + (i as f32 / (num_filters + 1) as f32) * (filter_max - filter_min);
+            scale_to_hz(point) / bin_hz
+        })
+        .collect();
+
+    let mut filter_bank = vec![vec![0.0; num_bins]; num_filters];
 
     for i in 0..num_filters {
-        let hz = scale_to_hz(filter_min + (i as f32 / num_filters as f32) * (filter_max - filter_min));
-        let j = (hz / scale).floor() as usize;
-        
-        if j < fft_size / 2 {
-            let hz_low = j as f32 * scale;
-            let hz_high = (j + 1) as f32 * scale;
-            let r = (hz - hz_low) / (hz_high - hz_low);
-            
-            filter_bank[i][j] = 1.0 - r;
-            if j + 1 < fft_size / 2 + 1 {
-                filter_bank[i][j + 1] = r;
-            }
+        let left = edge_bins[i];
+        let center = edge_bins[i + 1];
+        let right = edge_bins[i + 2];
+
+        for (bin, coeff) in filter_bank[i].iter_mut().enumerate() {
+            let bin = bin as f32;
+            *coeff = if center > left && bin >= left && bin <= center {
+                (bin - left) / (center - left)
+            } else if right > center && bin > center && bin <= right {
+                (right - bin) / (right - center)
+            } else {
+                0.0
+            };
         }
     }
 
     Ok(filter_bank)
 }
 
+/// Mel-frequency cepstral coefficients: takes the log of filter-bank band
+/// energies and applies a DCT-II to decorrelate them into a compact set of
+/// cepstral coefficients suitable for timbre/pitch visualization.
+#[wasm_bindgen]
+pub struct WasmMFCC {
+    num_filters: usize,
+    num_coeffs: usize,
+}
+
+#[wasm_bindgen]
+impl WasmMFCC {
+    #[wasm_bindgen(constructor)]
+    pub fn new(num_filters: usize, num_coeffs: usize) -> Result<WasmMFCC, JsValue> {
+        if num_coeffs == 0 || num_coeffs > num_filters {
+            return Err(JsValue::from_str(
+                "num_coeffs must be greater than 0 and at most num_filters",
+            ));
+        }
+
+        Ok(WasmMFCC {
+            num_filters,
+            num_coeffs,
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn apply(&self, filter_bank_energies: &[f32]) -> Result<Vec<f32>, JsValue> {
+        if filter_bank_energies.len() != self.num_filters {
+            return Err(JsValue::from_str(&format!(
+                "Expected {} filter bank energies, got {}",
+                self.num_filters,
+                filter_bank_energies.len()
+            )));
+        }
+
+        let log_energies: Vec<f32> = filter_bank_energies
+            .iter()
+            .map(|&e| e.max(1e-10).ln())
+            .collect();
+
+        let num_filters = self.num_filters as f32;
+        let mut coeffs = Vec::with_capacity(self.num_coeffs);
+
+        for k in 0..self.num_coeffs {
+            let mut sum = 0.0;
+            for (m, &log_energy) in log_energies.iter().enumerate() {
+                sum += log_energy * (PI * k as f32 * (m as f32 + 0.5) / num_filters).cos();
+            }
+            coeffs.push(sum);
+        }
+
+        Ok(coeffs)
+    }
+}
+
+/// A transposed direct-form II biquad (a0-normalized), with static
+/// constructors for the standard RBJ "Audio EQ Cookbook" filter shapes. Lets
+/// the plugin pre-emphasize or band-limit a signal before FFT analysis, or
+/// drive an analyzer EQ, entirely in WASM.
+#[wasm_bindgen]
+pub struct WasmBiquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    z1: f32,
+    z2: f32,
+}
+
+#[wasm_bindgen]
+impl WasmBiquad {
+    #[wasm_bindgen]
+    pub fn lowpass(sample_rate: f32, fc: f32, q: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let b1 = 1.0 - cos_w0;
+        let b0 = b1 / 2.0;
+        let b2 = b0;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    #[wasm_bindgen]
+    pub fn highpass(sample_rate: f32, fc: f32, q: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let b0 = (1.0 + cos_w0) / 2.0;
+        let b1 = -(1.0 + cos_w0);
+        let b2 = b0;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    #[wasm_bindgen]
+    pub fn bandpass(sample_rate: f32, fc: f32, q: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let b0 = alpha;
+        let b1 = 0.0;
+        let b2 = -alpha;
+        let a0 = 1.0 + alpha;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    #[wasm_bindgen]
+    pub fn peaking(sample_rate: f32, fc: f32, q: f32, gain_db: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let a = 10.0_f32.powf(gain_db / 40.0);
+        let b0 = 1.0 + alpha * a;
+        let b1 = -2.0 * cos_w0;
+        let b2 = 1.0 - alpha * a;
+        let a0 = 1.0 + alpha / a;
+        let a1 = -2.0 * cos_w0;
+        let a2 = 1.0 - alpha / a;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    #[wasm_bindgen(js_name = lowShelf)]
+    pub fn low_shelf(sample_rate: f32, fc: f32, q: f32, gain_db: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let a = 10.0_f32.powf(gain_db / 40.0);
+        let two_sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+        let b0 = a * ((a + 1.0) - (a - 1.0) * cos_w0 + two_sqrt_a_alpha);
+        let b1 = 2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0);
+        let b2 = a * ((a + 1.0) - (a - 1.0) * cos_w0 - two_sqrt_a_alpha);
+        let a0 = (a + 1.0) + (a - 1.0) * cos_w0 + two_sqrt_a_alpha;
+        let a1 = -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0);
+        let a2 = (a + 1.0) + (a - 1.0) * cos_w0 - two_sqrt_a_alpha;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    #[wasm_bindgen(js_name = highShelf)]
+    pub fn high_shelf(sample_rate: f32, fc: f32, q: f32, gain_db: f32) -> Result<WasmBiquad, JsValue> {
+        let (_, alpha, cos_w0) =
+            rbj_intermediates(sample_rate, fc, q).map_err(|e| JsValue::from_str(&e))?;
+        let a = 10.0_f32.powf(gain_db / 40.0);
+        let two_sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+        let b0 = a * ((a + 1.0) + (a - 1.0) * cos_w0 + two_sqrt_a_alpha);
+        let b1 = -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0);
+        let b2 = a * ((a + 1.0) + (a - 1.0) * cos_w0 - two_sqrt_a_alpha);
+        let a0 = (a + 1.0) - (a - 1.0) * cos_w0 + two_sqrt_a_alpha;
+        let a1 = 2.0 * ((a - 1.0) - (a + 1.0) * cos_w0);
+        let a2 = (a + 1.0) - (a - 1.0) * cos_w0 - two_sqrt_a_alpha;
+        Ok(WasmBiquad::normalized(b0, b1, b2, a0, a1, a2))
+    }
+
+    /// Processes `input` through the filter, carrying the two state samples
+    /// across calls so streaming chunks filter continuously.
+    #[wasm_bindgen]
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        let mut output = Vec::with_capacity(input.len());
+
+        for &x in input {
+            let y = self.b0 * x + self.z1;
+            self.z1 = self.b1 * x - self.a1 * y + self.z2;
+            self.z2 = self.b2 * x - self.a2 * y;
+            output.push(y);
+        }
+
+        output
+    }
+
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.z1 = 0.0;
+        self.z2 = 0.0;
+    }
+
+    fn normalized(b0: f32, b1: f32, b2: f32, a0: f32, a1: f32, a2: f32) -> WasmBiquad {
+        WasmBiquad {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+}
+
+/// Validates the RBJ filter parameters shared by every `WasmBiquad`
+/// constructor, then returns the cookbook intermediates `(w0, alpha,
+/// cos(w0))`. `q` must be positive (it divides `alpha`) and `fc` must sit
+/// strictly inside `(0, sample_rate / 2)` so `w0` stays inside `(0, pi)`;
+/// outside that range the cookbook formulas silently produce NaN/Inf
+/// coefficients. Errors are plain strings so the rejection path is testable
+/// with `cargo test` without constructing a `JsValue`.
+fn rbj_intermediates(sample_rate: f32, fc: f32, q: f32) -> Result<(f32, f32, f32), String> {
+    if q <= 0.0 {
+        return Err("q must be greater than 0".to_string());
+    }
+    if !(fc > 0.0 && fc < sample_rate / 2.0) {
+        return Err("fc must be greater than 0 and less than sample_rate / 2".to_string());
+    }
+
+    let w0 = 2.0 * PI * fc / sample_rate;
+    let alpha = w0.sin() / (2.0 * q);
+    Ok((w0, alpha, w0.cos()))
+}
+
+/// Plain (non-wasm_bindgen) validation so the rejection path is testable
+/// with `cargo test` without constructing a `JsValue`.
+fn color_indices_for_mapping(
+    spectrum: &[f32],
+    gain_db: f32,
+    range_db: f32,
+    mapping: &str,
+) -> Result<Vec<u8>, String> {
+    match mapping {
+        "linear" | "" => Ok(linear_color_indices(spectrum, gain_db, range_db)),
+        "logMeter" => Ok(log_meter_color_indices(spectrum, gain_db, range_db)),
+        mapping => Err(format!("Unknown mapping: {}", mapping)),
+    }
+}
+
 // Utility function to convert dB values to color indices
 #[wasm_bindgen]
 pub fn db_to_color_indices(
     spectrum: &[f32],
     gain_db: f32,
     range_db: f32,
-) -> Vec<u8> {
+    mapping: Option<&str>,
+) -> Result<Vec<u8>, JsValue> {
+    color_indices_for_mapping(spectrum, gain_db, range_db, mapping.unwrap_or("linear"))
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+fn linear_color_indices(spectrum: &[f32], gain_db: f32, range_db: f32) -> Vec<u8> {
     let mut color_indices = Vec::with_capacity(spectrum.len());
     let gain_plus_range = gain_db + range_db;
-    
+
     for &magnitude in spectrum {
         let magnitude = if magnitude > 1e-12 { magnitude } else { 1e-12 };
         let value_db = 20.0 * magnitude.log10();
-        
+
         let color_index = if value_db < -gain_plus_range {
             0
         } else if value_db > -gain_db {
@@ -328,9 +957,207 @@ pub fn db_to_color_indices(
         } else {
             ((value_db + gain_db) / range_db * 255.0 + 256.0).round() as u8
         };
-        
+
         color_indices.push(color_index);
     }
-    
+
     color_indices
-} 
\ No newline at end of file
+}
+
+/// Ardour's IEC-style `log_meter` curve: a piecewise-linear mapping from dB
+/// to a 0..1 deflection whose slope is shallowest below -60 dB and steepest
+/// from -20 dB up to +6 dB (where it saturates), so over half of the color
+/// range goes to that top region instead of a single linear ramp.
+fn log_meter(db: f32) -> f32 {
+    let deflection = if db < -70.0 {
+        0.0
+    } else if db < -60.0 {
+        (db + 70.0) * 0.25
+    } else if db < -50.0 {
+        (db + 60.0) * 0.5 + 2.5
+    } else if db < -40.0 {
+        (db + 50.0) * 0.75 + 7.5
+    } else if db < -30.0 {
+        (db + 40.0) * 1.5 + 15.0
+    } else if db < -20.0 {
+        (db + 30.0) * 2.0 + 30.0
+    } else if db < 6.0 {
+        (db + 20.0) * 2.5 + 50.0
+    } else {
+        115.0
+    };
+
+    (deflection / 115.0).clamp(0.0, 1.0)
+}
+
+fn log_meter_color_indices(spectrum: &[f32], gain_db: f32, range_db: f32) -> Vec<u8> {
+    let floor_db = -range_db;
+
+    spectrum
+        .iter()
+        .map(|&magnitude| {
+            let magnitude = if magnitude > 1e-12 { magnitude } else { 1e-12 };
+            let value_db = 20.0 * magnitude.log10() + gain_db;
+
+            if value_db < floor_db {
+                0
+            } else {
+                (log_meter(value_db) * 255.0).round() as u8
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculate_spectrum_recovers_known_sine_amplitude() {
+        let size = 64;
+        let bin = 4;
+        let amplitude = 2.0_f32;
+
+        let mut fft = WasmFFT::new(size, "rectangular", None).unwrap();
+        let signal: Vec<f32> = (0..size)
+            .map(|n| amplitude * (2.0 * PI * bin as f32 * n as f32 / size as f32).sin())
+            .collect();
+
+        let spectrum = fft.calculate_spectrum(&signal).unwrap();
+
+        assert!((spectrum[bin] - amplitude).abs() < 1e-3);
+        for (k, &magnitude) in spectrum.iter().enumerate() {
+            if k != bin {
+                assert!(magnitude < 1e-3, "unexpected energy at bin {k}: {magnitude}");
+            }
+        }
+    }
+
+    #[test]
+    fn calculate_spectrogram_reports_expected_frame_count() {
+        let size = 16;
+        let hop = 8;
+        let signal_len = 40;
+        let bins = size / 2;
+
+        let mut fft = WasmFFT::new(size, "rectangular", None).unwrap();
+        let signal = vec![0.0f32; signal_len];
+
+        let spectrogram = fft.calculate_spectrogram(&signal, hop).unwrap();
+
+        let expected_frames = 1 + (signal_len - size + hop - 1) / hop;
+        assert_eq!(fft.num_frames(), expected_frames);
+        assert_eq!(spectrogram.len(), expected_frames * bins);
+    }
+
+    #[test]
+    fn calculate_psd_scales_with_signal_power() {
+        let size = 32;
+        let sample_rate = 8000.0;
+        let mut fft = WasmFFT::new(size, "hann", None).unwrap();
+
+        let base: Vec<f32> = (0..size * 3)
+            .map(|n| (2.0 * PI * 5.0 * n as f32 / size as f32).sin())
+            .collect();
+        let doubled: Vec<f32> = base.iter().map(|&x| x * 2.0).collect();
+
+        let psd_base = fft.calculate_psd(&base, size / 2, sample_rate).unwrap();
+        let psd_doubled = fft.calculate_psd(&doubled, size / 2, sample_rate).unwrap();
+
+        let (peak_bin, &peak_base) = psd_base
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .unwrap();
+        let peak_doubled = psd_doubled[peak_bin];
+
+        // Doubling the amplitude should quadruple the power at the signal's peak.
+        assert!((peak_doubled / peak_base - 4.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn dpss_tapers_are_unit_energy_and_orthogonal() {
+        let tapers = generate_dpss_tapers(64, 3.0, 4);
+        assert_eq!(tapers.len(), 4);
+
+        for taper in &tapers {
+            let energy: f32 = taper.iter().map(|v| v * v).sum();
+            assert!((energy - 1.0).abs() < 1e-3);
+        }
+
+        for i in 0..tapers.len() {
+            for j in (i + 1)..tapers.len() {
+                let dot: f32 = tapers[i].iter().zip(&tapers[j]).map(|(a, b)| a * b).sum();
+                assert!(dot.abs() < 1e-2, "tapers {i} and {j} are not orthogonal: {dot}");
+            }
+        }
+    }
+
+    #[test]
+    fn filter_bank_filters_are_triangular_and_bounded() {
+        let filters = create_filter_bank(4, 32, 8000.0, "mel").unwrap();
+
+        for filter in &filters {
+            assert!(filter.iter().all(|&w| (0.0..=1.0 + 1e-6).contains(&w)));
+
+            // A true triangular filter rises monotonically to a single peak
+            // close to full weight, then falls monotonically back to zero.
+            let peak = filter.iter().cloned().fold(0.0_f32, f32::max);
+            assert!(peak > 0.5, "filter barely reaches its center weight: {peak}");
+
+            let peak_idx = filter.iter().position(|&w| w == peak).unwrap();
+            assert!(filter[..=peak_idx].windows(2).all(|w| w[1] >= w[0]));
+            assert!(filter[peak_idx..].windows(2).all(|w| w[1] <= w[0]));
+        }
+    }
+
+    #[test]
+    fn mfcc_first_coefficient_sums_log_energies() {
+        let mfcc = WasmMFCC::new(4, 2).unwrap();
+        let energies = [1.0_f32, 2.0, 4.0, 8.0];
+
+        let coeffs = mfcc.apply(&energies).unwrap();
+
+        // c[0] uses cos(0) = 1 for every band, so it's just the sum of the
+        // log energies.
+        let expected_c0: f32 = energies.iter().map(|e| e.ln()).sum();
+        assert!((coeffs[0] - expected_c0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn lowpass_biquad_has_unity_dc_gain() {
+        let mut biquad = WasmBiquad::lowpass(44100.0, 1000.0, 0.707).unwrap();
+        let input = vec![1.0_f32; 200];
+
+        let output = biquad.process(&input);
+
+        let steady_state = *output.last().unwrap();
+        assert!((steady_state - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn biquad_rejects_invalid_q_and_fc() {
+        // Every WasmBiquad constructor validates through rbj_intermediates.
+        assert!(rbj_intermediates(44100.0, 1000.0, 0.0).is_err());
+        assert!(rbj_intermediates(44100.0, 30000.0, 0.707).is_err());
+        assert!(rbj_intermediates(44100.0, 0.0, 0.707).is_err());
+    }
+
+    #[test]
+    fn log_meter_mapping_is_monotonic_and_saturates_at_high_db() {
+        // Magnitudes corresponding to roughly -120, -60, -20 and +10 dBFS.
+        let spectrum = [1e-6_f32, 1e-3, 1e-1, 3.1623];
+
+        let colors = db_to_color_indices(&spectrum, 0.0, 100.0, Some("logMeter")).unwrap();
+
+        for pair in colors.windows(2) {
+            assert!(pair[1] >= pair[0]);
+        }
+        assert_eq!(colors[3], 255);
+    }
+
+    #[test]
+    fn unknown_mapping_is_rejected() {
+        assert!(color_indices_for_mapping(&[0.5], 0.0, 100.0, "nonsense").is_err());
+    }
+}
\ No newline at end of file